@@ -0,0 +1,326 @@
+//! This crate provides vectors and operations
+//! on vectors which are probably worse than
+//! most other implementations.
+use std::ops::{Add, Sub, Mul, Div};
+/// This trait allows the creation of 2 dimensional vectors over various types.
+pub trait Vector2d<T>
+where T: PartialEq + PartialOrd
+       + Copy + Clone
+       + Add<Output=T> + Sub<Output=T>
+       + Mul<Output=T> + Div<Output=T>
+{
+    /// X value of first point.
+    fn xi(&self) -> T;
+    /// Y value of first point.
+    fn yi(&self) -> T;
+    /// X value of second point.
+    fn xf(&self) -> T;
+    /// Y value of second point.
+    fn yf(&self) -> T;
+    /// Associated helper function which checks for whether or not a number falls within a range
+    /// (inclusive).
+    fn in_range(mut a: T, mut b: T, c: T) -> bool {
+        if a > b { std::mem::swap(&mut a, &mut b); }
+        !(a > c || b < c)
+    }
+    /// Returns width of the vector. Signed.
+    fn w(&self) -> T { self.xf() - self.xi() }
+    /// Returns height of the vector. Signed.
+    fn h(&self) -> T { self.yf() - self.yi() }
+    /// Returns the slope of the vector.
+    fn delta(&self) -> T { self.h() / self.w() }
+    /// Returns whether or not this vector's domain includes the x value provided.
+    fn in_dom(&self, pt_x: T) -> bool { <Self as Vector2d<T>>::in_range(self.xi(), self.xf(), pt_x) }
+    /// Returns the y value of the line which is made by extending the ends of this vector.
+    fn linear(&self, pt_x: T) -> T { self.delta() * (pt_x - self.xi()) + self.yi() }
+    /// Returns the point at which two vectors cross, or None if they do not.
+    fn cross<O: Vector2d<T>>(&self, b: &O) -> Option<[T;2]> {
+        if self.delta() != b.delta() {
+            let insct = ((self.yi() - b.yi()) * self.w() * b.w()
+                          + b.h() * self.w() * b.xi() - self.h() * b.w() * self.xi()) /
+                         (b.h() * self.w() - self.h() * b.w());
+            if self.in_dom(insct) && b.in_dom(insct) { Some([insct, self.linear(insct)]) }
+            else { None }
+        } else { None }
+    }
+    /// Returns the point at which the two *finite* segments actually cross, or None if they
+    /// don't. Unlike `cross`, this never extends either vector into an infinite line and never
+    /// divides by a coordinate difference, so it also handles vertical and horizontal segments.
+    fn intersect<O: Vector2d<T>>(&self, other: &O) -> Option<[T;2]> {
+        let zero = self.xi() - self.xi();
+        let (d1x, d1y) = (self.w(), self.h());
+        let (d2x, d2y) = (other.w(), other.h());
+        let denom = d1x * d2y - d2x * d1y;
+        if denom == zero { return None; }
+        let dx = self.xi() - other.xi();
+        let dy = self.yi() - other.yi();
+        let num_s = d1x * dy - d1y * dx;
+        let num_t = d2x * dy - d2y * dx;
+        if <Self as Vector2d<T>>::in_range(zero, denom, num_s)
+            && <Self as Vector2d<T>>::in_range(zero, denom, num_t) {
+            let t = num_t / denom;
+            Some([self.xi() + d1x * t, self.yi() + d1y * t])
+        } else { None }
+    }
+    /// Returns the x coordinate of the point `t` of the way from the start of the vector to its
+    /// end, where `t = 0` is the start and `t = 1` is the end.
+    fn x(&self, t: T) -> T { self.xi() + self.w() * t }
+    /// Returns the y coordinate of the point `t` of the way from the start of the vector to its
+    /// end, where `t = 0` is the start and `t = 1` is the end.
+    fn y(&self, t: T) -> T { self.yi() + self.h() * t }
+    /// Returns the point `t` of the way along the vector, treating it as a curve parameterized
+    /// by `t` in `[0,1]`. Unlike `linear`, this needs no slope and so works on vertical vectors.
+    fn sample(&self, t: T) -> [T;2] { [self.x(t), self.y(t)] }
+    /// Returns the `t` for which `x(t)` equals the given x value, or zero if the vector has no
+    /// horizontal extent (avoiding a division by zero).
+    fn solve_t_for_x(&self, x: T) -> T {
+        let zero = self.xi() - self.xi();
+        if self.w() == zero { zero } else { (x - self.xi()) / self.w() }
+    }
+    /// Returns the `t` for which `y(t)` equals the given y value, or zero if the vector has no
+    /// vertical extent (avoiding a division by zero).
+    fn solve_t_for_y(&self, y: T) -> T {
+        let zero = self.xi() - self.xi();
+        if self.h() == zero { zero } else { (y - self.yi()) / self.h() }
+    }
+    /// Returns the dot product of this vector and another, treating both as displacements
+    /// (`w`,`h`) rather than as pairs of points.
+    fn dot<O: Vector2d<T>>(&self, other: &O) -> T { self.w() * other.w() + self.h() * other.h() }
+    /// Returns the squared length of the vector's displacement. Cheaper than `length` since it
+    /// avoids a square root, and useful when only comparing magnitudes.
+    fn length_squared(&self) -> T where Self: Sized { self.dot(self) }
+    /// Returns the axis-aligned bounding box of the vector as `[min_x, min_y, max_x, max_y]`,
+    /// ordering each axis's endpoints so it works regardless of the vector's direction.
+    fn bounds(&self) -> [T;4] {
+        let (mut min_x, mut max_x) = (self.xi(), self.xf());
+        if min_x > max_x { std::mem::swap(&mut min_x, &mut max_x); }
+        let (mut min_y, mut max_y) = (self.yi(), self.yf());
+        if min_y > max_y { std::mem::swap(&mut min_y, &mut max_y); }
+        [min_x, min_y, max_x, max_y]
+    }
+    /// Returns whether this vector's bounding box overlaps another's. This is a cheap O(1)
+    /// pre-filter: two segments can only cross if their boxes overlap, so callers can use this
+    /// to reject most pairs before running the full `intersect` math.
+    fn bbox_overlaps<O: Vector2d<T>>(&self, other: &O) -> bool {
+        let [a_min_x, a_min_y, a_max_x, a_max_y] = self.bounds();
+        let [b_min_x, b_min_y, b_max_x, b_max_y] = other.bounds();
+        let x_overlap = <Self as Vector2d<T>>::in_range(a_min_x, a_max_x, b_min_x)
+            || <Self as Vector2d<T>>::in_range(b_min_x, b_max_x, a_min_x);
+        let y_overlap = <Self as Vector2d<T>>::in_range(a_min_y, a_max_y, b_min_y)
+            || <Self as Vector2d<T>>::in_range(b_min_y, b_max_y, a_min_y);
+        x_overlap && y_overlap
+    }
+}
+impl<T> Vector2d<T> for (T,T,T,T)
+    where T: PartialEq + PartialOrd
+           + Copy + Clone
+           + Add<Output=T> + Sub<Output=T>
+           + Mul<Output=T> + Div<Output=T>
+{
+    fn xi(&self) -> T { self.0 }
+    fn yi(&self) -> T { self.1 }
+    fn xf(&self) -> T { self.2 }
+    fn yf(&self) -> T { self.3 }
+}
+impl<T> Vector2d<T> for [T;4]
+    where T: PartialEq + PartialOrd
+           + Copy + Clone
+           + Add<Output=T> + Sub<Output=T>
+           + Mul<Output=T> + Div<Output=T>
+{
+    fn xi(&self) -> T { self[0] }
+    fn yi(&self) -> T { self[1] }
+    fn xf(&self) -> T { self[2] }
+    fn yf(&self) -> T { self[3] }
+}
+
+/// Additional vector algebra that only makes sense for floating-point types, where length and
+/// angle are well defined. Blanket-implemented for every `Vector2d<T>` once `T` is a `Float`.
+pub trait Vector2dFloat<T: num_traits::Float>: Vector2d<T> {
+    /// Returns the length of the vector's displacement.
+    fn length(&self) -> T where Self: Sized { self.length_squared().sqrt() }
+    /// Returns the unit displacement in the same direction as this vector, as a `[T;4]` running
+    /// from this vector's origin.
+    fn normalize(&self) -> [T;4] where Self: Sized {
+        let len = self.length();
+        [self.xi(), self.yi(), self.xi() + self.w() / len, self.yi() + self.h() / len]
+    }
+    /// Returns the angle, in radians, from this vector's displacement to another's.
+    fn angle_to<O: Vector2d<T>>(&self, other: &O) -> T {
+        let perp_dot = self.w() * other.h() - self.h() * other.w();
+        perp_dot.atan2(self.dot(other))
+    }
+}
+impl<T: num_traits::Float, V: Vector2d<T>> Vector2dFloat<T> for V {}
+
+/// A closed 2-dimensional polygon given as an ordered list of vertices. This is the natural next
+/// abstraction above a single `Vector2d` segment: its edges are exposed as `[T;4]` segments, so
+/// the existing `cross`/`intersect` machinery can be run edge-by-edge against it.
+pub struct Polygon<T> {
+    pub vertices: Vec<[T;2]>,
+}
+impl<T> Polygon<T>
+    where T: PartialEq + PartialOrd
+           + Copy + Clone
+           + Add<Output=T> + Sub<Output=T>
+           + Mul<Output=T> + Div<Output=T>
+{
+    /// Creates a new polygon from an ordered list of vertices.
+    pub fn new(vertices: Vec<[T;2]>) -> Self { Polygon { vertices } }
+    /// Returns the polygon's edges as `Vector2d` segments, one per consecutive vertex pair,
+    /// wrapping from the last vertex back to the first.
+    pub fn edges(&self) -> impl Iterator<Item=[T;4]> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % n];
+            [a[0], a[1], b[0], b[1]]
+        })
+    }
+    /// Returns whether the point `(x, y)` lies inside the polygon. For each non-vertical edge
+    /// whose x range brackets `x`, compares `y` against the edge's y at that x (`g*(x-a.x)+a.y`
+    /// where `g` is the edge's slope) and toggles a side count; the point is inside when the
+    /// counts work out odd, per the standard even-odd rule.
+    pub fn contains(&self, x: T, y: T) -> bool {
+        let mut inside = false;
+        for [ax, ay, bx, by] in self.edges() {
+            if ax == bx { continue; }
+            if (ax > x) != (bx > x) {
+                let g = (ay - by) / (ax - bx);
+                let gy = g * (x - ax) + ay;
+                if y < gy { inside = !inside; }
+            }
+        }
+        inside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_cross() {
+        use Vector2d;
+        let v1 = [0.0f64, 0., 5., 5.];
+        let v2 = [5.0f64, 0., 0., 5.];
+        let p1 = v1.cross(&v2);
+        let p2 = v2.cross(&v1);
+        assert_eq!(p1,p2);
+    }
+
+    #[test]
+    fn test_intersect() {
+        use Vector2d;
+        let v1 = [0.0f64, 0., 5., 5.];
+        let v2 = [5.0f64, 0., 0., 5.];
+        assert_eq!(v1.intersect(&v2), Some([2.5, 2.5]));
+    }
+
+    #[test]
+    fn test_intersect_vertical() {
+        use Vector2d;
+        let v1 = [2.0f64, 0., 2., 5.];
+        let v2 = [0.0f64, 2., 5., 2.];
+        assert_eq!(v1.intersect(&v2), Some([2., 2.]));
+    }
+
+    #[test]
+    fn test_intersect_outside_segment() {
+        use Vector2d;
+        let v1 = [0.0f64, 0., 1., 1.];
+        let v2 = [5.0f64, 0., 4., 1.];
+        assert_eq!(v1.intersect(&v2), None);
+    }
+
+    #[test]
+    fn test_intersect_parallel() {
+        use Vector2d;
+        let v1 = [0.0f64, 0., 5., 5.];
+        let v2 = [0.0f64, 1., 5., 6.];
+        assert_eq!(v1.intersect(&v2), None);
+    }
+
+    #[test]
+    fn test_sample() {
+        use Vector2d;
+        let v = [0.0f64, 0., 10., 20.];
+        assert_eq!(v.sample(0.5), [5., 10.]);
+        assert_eq!(v.x(0.5), 5.);
+        assert_eq!(v.y(0.5), 10.);
+    }
+
+    #[test]
+    fn test_solve_t() {
+        use Vector2d;
+        let v = [0.0f64, 0., 10., 20.];
+        assert_eq!(v.solve_t_for_x(5.), 0.5);
+        assert_eq!(v.solve_t_for_y(10.), 0.5);
+    }
+
+    #[test]
+    fn test_solve_t_degenerate() {
+        use Vector2d;
+        let v = [2.0f64, 0., 2., 5.];
+        assert_eq!(v.solve_t_for_x(2.), 0.);
+    }
+
+    #[test]
+    fn test_dot_and_length_squared() {
+        use Vector2d;
+        let v = [0.0f64, 0., 3., 4.];
+        assert_eq!(v.dot(&v), 25.);
+        assert_eq!(v.length_squared(), 25.);
+    }
+
+    #[test]
+    fn test_length_and_normalize() {
+        use Vector2dFloat;
+        let v = [0.0f64, 0., 3., 4.];
+        assert_eq!(v.length(), 5.);
+        assert_eq!(v.normalize(), [0., 0., 0.6, 0.8]);
+    }
+
+    #[test]
+    fn test_angle_to() {
+        use Vector2dFloat;
+        let v1 = [0.0f64, 0., 1., 0.];
+        let v2 = [0.0f64, 0., 0., 1.];
+        assert_eq!(v1.angle_to(&v2), std::f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_bounds() {
+        use Vector2d;
+        let v = [5.0f64, 5., 0., 0.];
+        assert_eq!(v.bounds(), [0., 0., 5., 5.]);
+    }
+
+    #[test]
+    fn test_bbox_overlaps() {
+        use Vector2d;
+        let v1 = [0.0f64, 0., 5., 5.];
+        let v2 = [4.0f64, 4., 8., 8.];
+        let v3 = [6.0f64, 6., 8., 8.];
+        assert!(v1.bbox_overlaps(&v2));
+        assert!(!v1.bbox_overlaps(&v3));
+    }
+
+    #[test]
+    fn test_polygon_contains() {
+        use Polygon;
+        let square = Polygon::new(vec![[0.0f64, 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        assert!(square.contains(2., 2.));
+        assert!(!square.contains(5., 5.));
+    }
+
+    #[test]
+    fn test_polygon_edges() {
+        use Polygon;
+        let triangle = Polygon::new(vec![[0.0f64, 0.], [4., 0.], [0., 4.]]);
+        let edges: Vec<[f64;4]> = triangle.edges().collect();
+        assert_eq!(edges, vec![
+            [0., 0., 4., 0.],
+            [4., 0., 0., 4.],
+            [0., 4., 0., 0.],
+        ]);
+    }
+}